@@ -4,14 +4,44 @@
 #![deny(missing_docs)]
 #![cfg_attr(not(test), no_std)]
 
+use core::marker::PhantomData;
+
 use embedded_io::ErrorType;
 
-/// A RingBuffer holds SIZE elements of type T.
-pub struct RingBuffer<T, const SIZE: usize> {
-    data: [T; SIZE],
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Overwrite policy for a [`RingBuffer`], fixed at the type level.
+///
+/// This trait is sealed: [`Bounded`] and [`Unbounded`] are its only implementors, so a
+/// buffer's overwrite behavior can never be redefined by downstream code, and callers can
+/// rely on [`RingBuffer::push`]'s signature alone to know whether it rejects or evicts.
+pub trait OverflowPolicy: sealed::Sealed {}
+
+/// Policy marker: [`RingBuffer::push`] rejects the new element once the buffer is full.
+pub struct Bounded;
+
+/// Policy marker: [`RingBuffer::push`] evicts the oldest element once the buffer is full.
+pub struct Unbounded;
+
+impl sealed::Sealed for Bounded {}
+impl sealed::Sealed for Unbounded {}
+impl OverflowPolicy for Bounded {}
+impl OverflowPolicy for Unbounded {}
+
+/// A RingBuffer holds SIZE elements of type T, with its overwrite policy `P` fixed at the
+/// type level (see [`Bounded`]/[`Unbounded`]).
+///
+/// Slots are stored as `Option<T>` so the buffer can hold any `T`, including types without a
+/// `Default` impl or move-only payloads; pushes move values in by storing `Some(elem)` and pops
+/// move them out via `Option::take`, rather than requiring `T: Copy`.
+pub struct RingBuffer<T, P: OverflowPolicy, const SIZE: usize> {
+    data: [Option<T>; SIZE],
 
     oldest: usize,
     num_elems: usize,
+    policy: PhantomData<P>,
 }
 
 /// Errors while handling a RingBuffer
@@ -22,44 +52,47 @@ pub enum Error {
     BufferFull,
 }
 
-impl<T: Default + Copy, const SIZE: usize> Default for RingBuffer<T, SIZE> {
+impl<T, P: OverflowPolicy, const SIZE: usize> Default for RingBuffer<T, P, SIZE> {
     fn default() -> Self {
         RingBuffer {
-            data: [T::default(); SIZE],
+            data: core::array::from_fn(|_| None),
             oldest: 0,
             num_elems: 0,
+            policy: PhantomData,
         }
     }
 }
 
-impl<T: Default + Copy, const SIZE: usize> RingBuffer<T, SIZE> {
+impl<T, P: OverflowPolicy, const SIZE: usize> RingBuffer<T, P, SIZE> {
     /// Make a new RingBuffer!
     pub fn new() -> Self {
         Default::default()
     }
 
     /// Push something onto the buffer. If the buffer is full, the oldest element is returned.
-    pub fn push_overwrite(&mut self, elem: T) -> Option<T> {
+    /// Crate-private [`Unbounded`] primitive behind [`RingBuffer::push`].
+    pub(crate) fn push_overwrite(&mut self, elem: T) -> Option<T> {
         let index = (self.oldest + self.num_elems) % SIZE;
         if self.is_full() {
-            let oldest = self.data[self.oldest];
+            let oldest = self.data[self.oldest].take();
             self.oldest = (self.oldest + 1) % SIZE;
-            self.data[index] = elem;
-            Some(oldest)
+            self.data[index] = Some(elem);
+            oldest
         } else {
-            self.data[index] = elem;
+            self.data[index] = Some(elem);
             self.num_elems += 1;
             None
         }
     }
 
     /// Push something onto the buffer, unless the buffer is full.
-    pub fn push_unless_full(&mut self, elem: T) -> Result<(), Error> {
+    /// Crate-private [`Bounded`] primitive behind [`RingBuffer::push`].
+    pub(crate) fn push_unless_full(&mut self, elem: T) -> Result<(), Error> {
         if self.is_full() {
             return Err(Error::BufferFull);
         }
         let index = (self.oldest + self.num_elems) % SIZE;
-        self.data[index] = elem;
+        self.data[index] = Some(elem);
         self.num_elems += 1;
         Ok(())
     }
@@ -69,10 +102,10 @@ impl<T: Default + Copy, const SIZE: usize> RingBuffer<T, SIZE> {
         if self.is_empty() {
             return None;
         }
-        let elem = self.data[self.oldest];
+        let elem = self.data[self.oldest].take();
         self.oldest = (self.oldest + 1) % SIZE;
         self.num_elems -= 1;
-        Some(elem)
+        elem
     }
 
     /// Peek at the next element in the buffer. None if buffer empty.
@@ -80,8 +113,7 @@ impl<T: Default + Copy, const SIZE: usize> RingBuffer<T, SIZE> {
         if self.is_empty() {
             return None;
         }
-        let elem = &self.data[self.oldest];
-        Some(elem)
+        self.data[self.oldest].as_ref()
     }
 
     /// How many elements are in the buffer?
@@ -99,6 +131,16 @@ impl<T: Default + Copy, const SIZE: usize> RingBuffer<T, SIZE> {
         self.len() == SIZE
     }
 
+    /// How many elements does this buffer hold in total?
+    pub fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    /// How many more elements can be pushed before the buffer is full?
+    pub fn remaining(&self) -> usize {
+        SIZE - self.num_elems
+    }
+
     /// Pop some elements into the buffer
     pub fn pop_many(&mut self, buf: &mut [T]) -> usize {
         let count = usize::min(self.len(), buf.len());
@@ -107,23 +149,187 @@ impl<T: Default + Copy, const SIZE: usize> RingBuffer<T, SIZE> {
         }
         count
     }
+
+    /// Returns the buffer's contents as up to two iterators over contiguous runs, in
+    /// oldest-to-newest order.
+    ///
+    /// The first iterator yields the contiguous run starting at the oldest element; the
+    /// second yields the remainder if the contents wrap around the end of the backing array.
+    /// An empty buffer returns two empty iterators.
+    pub fn as_slices(&self) -> (impl Iterator<Item = &T>, impl Iterator<Item = &T>) {
+        let (front, back): (&[Option<T>], &[Option<T>]) = if self.num_elems == 0 {
+            (&[], &[])
+        } else if self.oldest + self.num_elems <= SIZE {
+            (&self.data[self.oldest..self.oldest + self.num_elems], &[])
+        } else {
+            let wrapped = self.oldest + self.num_elems - SIZE;
+            (&self.data[self.oldest..SIZE], &self.data[0..wrapped])
+        };
+        (
+            front.iter().filter_map(Option::as_ref),
+            back.iter().filter_map(Option::as_ref),
+        )
+    }
+
+    /// Mutable counterpart to [`RingBuffer::as_slices`], as two iterators over direct
+    /// references to each occupied element.
+    ///
+    /// These yield `&mut T` rather than `&mut Option<T>`: callers can overwrite a slot's
+    /// value, but unlike a raw `&mut Option<T>` they have no way to vacate the slot (e.g. via
+    /// `Option::take`) without going through `pop`/`pop_back`, which keeps `oldest`/`num_elems`
+    /// in sync with what's actually stored.
+    pub fn as_mut_slices(
+        &mut self,
+    ) -> (impl Iterator<Item = &mut T>, impl Iterator<Item = &mut T>) {
+        let (front, back): (&mut [Option<T>], &mut [Option<T>]) = if self.num_elems == 0 {
+            (&mut [], &mut [])
+        } else if self.oldest + self.num_elems <= SIZE {
+            (
+                &mut self.data[self.oldest..self.oldest + self.num_elems],
+                &mut [],
+            )
+        } else {
+            let wrapped = self.oldest + self.num_elems - SIZE;
+            let (front, back) = self.data.split_at_mut(self.oldest);
+            (back, &mut front[0..wrapped])
+        };
+        (
+            front.iter_mut().filter_map(Option::as_mut),
+            back.iter_mut().filter_map(Option::as_mut),
+        )
+    }
+
+    /// Push something onto the front of the buffer, unless the buffer is full.
+    /// Crate-private [`Bounded`] primitive behind [`RingBuffer::push_front`].
+    pub(crate) fn push_front_unless_full(&mut self, elem: T) -> Result<(), Error> {
+        if self.is_full() {
+            return Err(Error::BufferFull);
+        }
+        self.oldest = (self.oldest + SIZE - 1) % SIZE;
+        self.data[self.oldest] = Some(elem);
+        self.num_elems += 1;
+        Ok(())
+    }
+
+    /// Push something onto the front of the buffer. If the buffer is full, the newest element is returned.
+    /// Crate-private [`Unbounded`] primitive behind [`RingBuffer::push_front`].
+    pub(crate) fn push_front_overwrite(&mut self, elem: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            let newest = (self.oldest + self.num_elems - 1) % SIZE;
+            self.data[newest].take()
+        } else {
+            self.num_elems += 1;
+            None
+        };
+        self.oldest = (self.oldest + SIZE - 1) % SIZE;
+        self.data[self.oldest] = Some(elem);
+        evicted
+    }
+
+    /// Pop the newest element off the back of the buffer. If it is empty, then None is returned.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = (self.oldest + self.num_elems - 1) % SIZE;
+        self.num_elems -= 1;
+        self.data[index].take()
+    }
+
+    /// Empties the buffer, dropping any stored elements, without reallocating the backing storage.
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T: Copy, P: OverflowPolicy, const SIZE: usize> RingBuffer<T, P, SIZE> {
+    /// Peek at the next element in the buffer and copy it out. None if buffer empty.
+    ///
+    /// A `Copy`-bound fast path for callers who would otherwise immediately dereference the
+    /// reference returned by [`RingBuffer::peek`].
+    pub fn peek_copied(&self) -> Option<T> {
+        self.peek().copied()
+    }
+}
+
+impl<T: PartialEq, P1: OverflowPolicy, const SIZE1: usize> RingBuffer<T, P1, SIZE1> {
+    /// Compares just the element sequences of two buffers, in oldest-to-newest order, even if
+    /// they have a different `SIZE` or overwrite policy.
+    pub fn elem_equal<P2: OverflowPolicy, const SIZE2: usize>(
+        &self,
+        other: &RingBuffer<T, P2, SIZE2>,
+    ) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq, P1: OverflowPolicy, P2: OverflowPolicy, const SIZE: usize>
+    PartialEq<RingBuffer<T, P2, SIZE>> for RingBuffer<T, P1, SIZE>
+{
+    fn eq(&self, other: &RingBuffer<T, P2, SIZE>) -> bool {
+        self.elem_equal(other)
+    }
+}
+
+impl<T: Eq, P: OverflowPolicy, const SIZE: usize> Eq for RingBuffer<T, P, SIZE> {}
+
+impl<T: core::fmt::Debug, P: OverflowPolicy, const SIZE: usize> core::fmt::Debug
+    for RingBuffer<T, P, SIZE>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RingBuffer")
+            .field("data", &self.data)
+            .field("oldest", &self.oldest)
+            .field("num_elems", &self.num_elems)
+            .finish()
+    }
+}
+
+impl<T, const SIZE: usize> RingBuffer<T, Bounded, SIZE> {
+    /// Push onto the buffer. Rejects the new element with [`Error::BufferFull`] if the
+    /// buffer is full, since this buffer's policy is [`Bounded`].
+    pub fn push(&mut self, elem: T) -> Result<(), Error> {
+        self.push_unless_full(elem)
+    }
+
+    /// Push onto the front of the buffer. Rejects the new element with [`Error::BufferFull`]
+    /// if the buffer is full, since this buffer's policy is [`Bounded`].
+    pub fn push_front(&mut self, elem: T) -> Result<(), Error> {
+        self.push_front_unless_full(elem)
+    }
+}
+
+impl<T, const SIZE: usize> RingBuffer<T, Unbounded, SIZE> {
+    /// Push onto the buffer. Evicts and returns the oldest element if the buffer is full,
+    /// since this buffer's policy is [`Unbounded`].
+    pub fn push(&mut self, elem: T) -> Option<T> {
+        self.push_overwrite(elem)
+    }
+
+    /// Push onto the front of the buffer. Evicts and returns the newest element if the
+    /// buffer is full, since this buffer's policy is [`Unbounded`].
+    pub fn push_front(&mut self, elem: T) -> Option<T> {
+        self.push_front_overwrite(elem)
+    }
 }
 
 /// Consuming IntoIterator for Ringbuffer
-pub struct ConsumingIntoIteratorRingbuffer<T, const SIZE: usize> {
-    buffer: RingBuffer<T, SIZE>,
+pub struct ConsumingIntoIteratorRingbuffer<T, P: OverflowPolicy, const SIZE: usize> {
+    buffer: RingBuffer<T, P, SIZE>,
 }
 
-impl<T: Default + Copy, const SIZE: usize> IntoIterator for RingBuffer<T, SIZE> {
+impl<T, P: OverflowPolicy, const SIZE: usize> IntoIterator for RingBuffer<T, P, SIZE> {
     type Item = T;
-    type IntoIter = ConsumingIntoIteratorRingbuffer<T, SIZE>;
+    type IntoIter = ConsumingIntoIteratorRingbuffer<T, P, SIZE>;
 
     fn into_iter(self) -> Self::IntoIter {
         ConsumingIntoIteratorRingbuffer { buffer: self }
     }
 }
 
-impl<T: Default + Copy, const SIZE: usize> Iterator for ConsumingIntoIteratorRingbuffer<T, SIZE> {
+impl<T, P: OverflowPolicy, const SIZE: usize> Iterator
+    for ConsumingIntoIteratorRingbuffer<T, P, SIZE>
+{
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -131,47 +337,72 @@ impl<T: Default + Copy, const SIZE: usize> Iterator for ConsumingIntoIteratorRin
     }
 }
 
+impl<T, P: OverflowPolicy, const SIZE: usize> DoubleEndedIterator
+    for ConsumingIntoIteratorRingbuffer<T, P, SIZE>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_back()
+    }
+}
+
 /// IntoIterator for Ringbuffer
-pub struct IntoIteratorRingbuffer<'a, T, const SIZE: usize> {
-    buffer: &'a RingBuffer<T, SIZE>,
-    current: usize,
+pub struct IntoIteratorRingbuffer<'a, T, P: OverflowPolicy, const SIZE: usize> {
+    buffer: &'a RingBuffer<T, P, SIZE>,
+    front: usize,
+    back: usize,
 }
 
-impl<'a, T: Default + Copy, const SIZE: usize> IntoIterator for &'a RingBuffer<T, SIZE> {
+impl<'a, T, P: OverflowPolicy, const SIZE: usize> IntoIterator for &'a RingBuffer<T, P, SIZE> {
     type Item = &'a T;
-    type IntoIter = IntoIteratorRingbuffer<'a, T, SIZE>;
+    type IntoIter = IntoIteratorRingbuffer<'a, T, P, SIZE>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIteratorRingbuffer {
             buffer: self,
-            current: 0,
+            front: 0,
+            back: self.num_elems,
         }
     }
 }
 
-impl<'a, T: Default + Copy, const SIZE: usize> Iterator for IntoIteratorRingbuffer<'a, T, SIZE> {
+impl<'a, T, P: OverflowPolicy, const SIZE: usize> Iterator
+    for IntoIteratorRingbuffer<'a, T, P, SIZE>
+{
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current < self.buffer.num_elems {
-            let index = (self.buffer.oldest + self.current) % SIZE;
-            let elem = &self.buffer.data[index];
-            self.current += 1;
-            Some(elem)
+        if self.front < self.back {
+            let index = (self.buffer.oldest + self.front) % SIZE;
+            self.front += 1;
+            self.buffer.data[index].as_ref()
         } else {
             None
         }
     }
 }
 
-impl<T: Default + Copy, const SIZE: usize> RingBuffer<T, SIZE> {
+impl<'a, T, P: OverflowPolicy, const SIZE: usize> DoubleEndedIterator
+    for IntoIteratorRingbuffer<'a, T, P, SIZE>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            let index = (self.buffer.oldest + self.back) % SIZE;
+            self.buffer.data[index].as_ref()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, P: OverflowPolicy, const SIZE: usize> RingBuffer<T, P, SIZE> {
     /// Make an immutable non-consuming iterator
-    pub fn iter(&self) -> IntoIteratorRingbuffer<T, SIZE> {
+    pub fn iter(&self) -> IntoIteratorRingbuffer<T, P, SIZE> {
         self.into_iter()
     }
 }
 
-impl<T, const SIZE: usize> ErrorType for RingBuffer<T, SIZE> {
+impl<T, P: OverflowPolicy, const SIZE: usize> ErrorType for RingBuffer<T, P, SIZE> {
     type Error = crate::Error;
 }
 
@@ -183,19 +414,49 @@ impl embedded_io::Error for crate::Error {
     }
 }
 
-impl<const SIZE: usize> embedded_io::Read for RingBuffer<u8, SIZE> {
+impl<P: OverflowPolicy, const SIZE: usize> embedded_io::Read for RingBuffer<u8, P, SIZE> {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
         Ok(self.pop_many(buf))
     }
 }
 
+impl<const SIZE: usize> embedded_io::Write for RingBuffer<u8, Bounded, SIZE> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let mut written = 0;
+        for &byte in buf {
+            if self.push_unless_full(byte).is_err() {
+                break;
+            }
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<const SIZE: usize> embedded_io::Write for RingBuffer<u8, Unbounded, SIZE> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        for &byte in buf {
+            self.push_overwrite(byte);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn it_works() {
-        let mut buffer = RingBuffer::<u16, 8>::new();
+        let mut buffer = RingBuffer::<u16, Bounded, 8>::new();
         assert!(buffer.is_empty());
 
         buffer.push_overwrite(1);
@@ -207,13 +468,13 @@ mod tests {
 
     #[test]
     fn pop_empty() {
-        let mut buffer = RingBuffer::<u8, 8>::new();
+        let mut buffer = RingBuffer::<u8, Bounded, 8>::new();
         assert_eq!(buffer.pop(), None);
     }
 
     #[test]
     fn single_element() {
-        let mut buffer = RingBuffer::<u8, 1>::new();
+        let mut buffer = RingBuffer::<u8, Bounded, 1>::new();
         assert!(buffer.is_empty());
         buffer.push_overwrite(1);
         assert!(buffer.is_full());
@@ -225,7 +486,7 @@ mod tests {
 
     #[test]
     fn consuming_iterator() {
-        let mut buffer = RingBuffer::<u8, 8>::new();
+        let mut buffer = RingBuffer::<u8, Bounded, 8>::new();
         buffer.push_overwrite(5);
         buffer.push_overwrite(125);
         buffer.push_overwrite(0);
@@ -239,7 +500,7 @@ mod tests {
 
     #[test]
     fn iterator_immutable() {
-        let mut buffer = RingBuffer::<u8, 4>::new();
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
         buffer.push_overwrite(1);
         buffer.push_overwrite(2);
         buffer.push_overwrite(3);
@@ -257,7 +518,7 @@ mod tests {
 
     #[test]
     fn iter_convenience() {
-        let mut buffer = RingBuffer::<u8, 4>::new();
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
 
         buffer.push_overwrite(1);
         buffer.push_overwrite(2);
@@ -273,7 +534,7 @@ mod tests {
 
     #[test]
     fn push_overwrite() {
-        let mut buffer = RingBuffer::<u128, 4>::new();
+        let mut buffer = RingBuffer::<u128, Bounded, 4>::new();
         assert!(buffer.push_overwrite(1).is_none());
         assert!(buffer.push_overwrite(2).is_none());
         assert!(buffer.push_overwrite(3).is_none());
@@ -289,7 +550,7 @@ mod tests {
 
     #[test]
     fn fail_overwrite() {
-        let mut buffer = RingBuffer::<u128, 4>::new();
+        let mut buffer = RingBuffer::<u128, Bounded, 4>::new();
         assert!(buffer.push_overwrite(1).is_none());
         assert!(buffer.push_overwrite(2).is_none());
         assert!(buffer.push_overwrite(3).is_none());
@@ -308,7 +569,7 @@ mod tests {
 
     #[test]
     fn pop_many() {
-        let mut buffer = RingBuffer::<u8, 4>::new();
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
         buffer.push_overwrite(1);
         buffer.push_overwrite(2);
         buffer.push_overwrite(3);
@@ -324,4 +585,264 @@ mod tests {
         assert_eq!(0, buffer.pop_many(two));
         assert_eq!(two, &[3, 4]);
     }
+
+    #[test]
+    fn as_slices_empty() {
+        let buffer = RingBuffer::<u8, Bounded, 4>::new();
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), Vec::<u8>::new());
+        assert_eq!(back.copied().collect::<Vec<_>>(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn as_slices_contiguous() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        buffer.push_overwrite(1);
+        buffer.push_overwrite(2);
+        buffer.push_overwrite(3);
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(back.copied().collect::<Vec<_>>(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn as_slices_wrapped() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        for i in 1..=6 {
+            buffer.push_overwrite(i);
+        }
+        // Buffer now holds [3, 4, 5, 6], wrapped around the backing array.
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(back.copied().collect::<Vec<_>>(), vec![5, 6]);
+    }
+
+    #[test]
+    fn as_mut_slices_wrapped() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        for i in 1..=6 {
+            buffer.push_overwrite(i);
+        }
+        let (front, back) = buffer.as_mut_slices();
+        for elem in front.chain(back) {
+            *elem *= 10;
+        }
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), vec![30, 40]);
+        assert_eq!(back.copied().collect::<Vec<_>>(), vec![50, 60]);
+    }
+
+    #[test]
+    fn push_front_and_pop_back() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        buffer.push_front(1).unwrap();
+        buffer.push_front(2).unwrap();
+        buffer.push_front(3).unwrap();
+        // Logical order is newest-at-front: [3, 2, 1].
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+        assert_eq!(back.copied().collect::<Vec<_>>(), Vec::<u8>::new());
+        assert_eq!(3, buffer.pop().unwrap());
+        assert_eq!(1, buffer.pop_back().unwrap());
+        assert_eq!(2, buffer.pop().unwrap());
+        assert_eq!(None, buffer.pop_back());
+    }
+
+    #[test]
+    fn push_front_full() {
+        let mut buffer = RingBuffer::<u8, Bounded, 2>::new();
+        buffer.push_front(1).unwrap();
+        buffer.push_front(2).unwrap();
+        assert!(buffer.push_front(3).is_err());
+    }
+
+    #[test]
+    fn push_front_overwrite_evicts_newest() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        buffer.push_overwrite(1);
+        buffer.push_overwrite(2);
+        buffer.push_overwrite(3);
+        buffer.push_overwrite(4);
+        assert_eq!(4, buffer.push_front_overwrite(0).unwrap());
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), vec![0]);
+        assert_eq!(back.copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reverse_iteration() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        buffer.push_overwrite(1);
+        buffer.push_overwrite(2);
+        buffer.push_overwrite(3);
+        let mut iter = buffer.iter().rev();
+        assert_eq!(&3, iter.next().unwrap());
+        assert_eq!(&2, iter.next().unwrap());
+        assert_eq!(&1, iter.next().unwrap());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn reverse_consuming_iteration() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        buffer.push_overwrite(1);
+        buffer.push_overwrite(2);
+        buffer.push_overwrite(3);
+        let mut iter = std::iter::IntoIterator::into_iter(buffer).rev();
+        assert_eq!(3, iter.next().unwrap());
+        assert_eq!(2, iter.next().unwrap());
+        assert_eq!(1, iter.next().unwrap());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn non_copy_element() {
+        let mut buffer = RingBuffer::<String, Bounded, 2>::new();
+        buffer.push_unless_full(String::from("a")).unwrap();
+        buffer.push_unless_full(String::from("b")).unwrap();
+        assert_eq!(Some(String::from("a")), buffer.pop());
+        assert_eq!(Some(String::from("b")), buffer.pop());
+        assert_eq!(None, buffer.pop());
+    }
+
+    #[test]
+    fn peek_copied() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        assert_eq!(None, buffer.peek_copied());
+        buffer.push_overwrite(42);
+        assert_eq!(Some(42), buffer.peek_copied());
+    }
+
+    #[test]
+    fn equality_ignores_rotation() {
+        let mut a = RingBuffer::<u8, Bounded, 4>::new();
+        a.push_overwrite(1);
+        a.push_overwrite(2);
+        a.push_overwrite(3);
+
+        // Rotate b so its contents wrap around the backing array, unlike a's.
+        let mut b = RingBuffer::<u8, Bounded, 4>::new();
+        b.push_overwrite(9);
+        b.push_overwrite(9);
+        b.pop();
+        b.pop();
+        b.push_overwrite(1);
+        b.push_overwrite(2);
+        b.push_overwrite(3);
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn inequality() {
+        let mut a = RingBuffer::<u8, Bounded, 4>::new();
+        a.push_overwrite(1);
+        a.push_overwrite(2);
+
+        let mut b = RingBuffer::<u8, Bounded, 4>::new();
+        b.push_overwrite(1);
+        b.push_overwrite(3);
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn elem_equal_across_sizes_and_policies() {
+        let mut a = RingBuffer::<u8, Bounded, 4>::new();
+        a.push_overwrite(1);
+        a.push_overwrite(2);
+
+        let mut b = RingBuffer::<u8, Unbounded, 8>::new();
+        b.push_overwrite(1);
+        b.push_overwrite(2);
+
+        assert!(a.elem_equal(&b));
+    }
+
+    #[test]
+    fn bounded_push_rejects_when_full() {
+        let mut buffer = RingBuffer::<u8, Bounded, 2>::new();
+        assert!(buffer.push(1).is_ok());
+        assert!(buffer.push(2).is_ok());
+        assert!(buffer.push(3).is_err());
+        assert_eq!(1, buffer.pop().unwrap());
+    }
+
+    #[test]
+    fn capacity_and_remaining() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        assert_eq!(4, buffer.capacity());
+        assert_eq!(4, buffer.remaining());
+        buffer.push_overwrite(1);
+        buffer.push_overwrite(2);
+        assert_eq!(4, buffer.capacity());
+        assert_eq!(2, buffer.remaining());
+    }
+
+    #[test]
+    fn clear() {
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        buffer.push_overwrite(1);
+        buffer.push_overwrite(2);
+        buffer.push_overwrite(3);
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(4, buffer.remaining());
+        buffer.push_overwrite(4);
+        assert_eq!(4, buffer.pop().unwrap());
+    }
+
+    #[test]
+    fn embedded_io_write() {
+        use embedded_io::Write;
+
+        let mut buffer = RingBuffer::<u8, Bounded, 4>::new();
+        assert_eq!(3, buffer.write(&[1, 2, 3]).unwrap());
+        assert_eq!(1, buffer.write(&[4, 5]).unwrap());
+        assert_eq!(0, buffer.write(&[6]).unwrap());
+        buffer.flush().unwrap();
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(back.copied().collect::<Vec<_>>(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn embedded_io_write_unbounded_evicts() {
+        use embedded_io::Write;
+
+        let mut buffer = RingBuffer::<u8, Unbounded, 4>::new();
+        assert_eq!(6, buffer.write(&[1, 2, 3, 4, 5, 6]).unwrap());
+        let (front, back) = buffer.as_slices();
+        assert_eq!(front.copied().collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(back.copied().collect::<Vec<_>>(), vec![5, 6]);
+    }
+
+    #[test]
+    fn unbounded_push_evicts_when_full() {
+        let mut buffer = RingBuffer::<u8, Unbounded, 2>::new();
+        assert_eq!(None, buffer.push(1));
+        assert_eq!(None, buffer.push(2));
+        assert_eq!(Some(1), buffer.push(3));
+        assert_eq!(2, buffer.pop().unwrap());
+        assert_eq!(3, buffer.pop().unwrap());
+    }
+
+    #[test]
+    fn unbounded_push_front_evicts_when_full() {
+        let mut buffer = RingBuffer::<u8, Unbounded, 2>::new();
+        assert_eq!(None, buffer.push_front(1));
+        assert_eq!(None, buffer.push_front(2));
+        assert_eq!(Some(1), buffer.push_front(3));
+        assert_eq!(3, buffer.pop().unwrap());
+        assert_eq!(2, buffer.pop().unwrap());
+    }
+
+    #[test]
+    fn bounded_push_front_rejects_when_full() {
+        let mut buffer = RingBuffer::<u8, Bounded, 2>::new();
+        assert!(buffer.push_front(1).is_ok());
+        assert!(buffer.push_front(2).is_ok());
+        assert!(buffer.push_front(3).is_err());
+        assert_eq!(2, buffer.pop().unwrap());
+    }
 }